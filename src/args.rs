@@ -0,0 +1,206 @@
+use clap::{Parser, Subcommand};
+use clap_complete::Shell;
+
+/// A command line tool for downloading, viewing, and submitting
+/// Advent of Code puzzles.
+#[derive(Debug, Parser)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    /// Disable all output except errors
+    #[arg(short, long, global = true, conflicts_with = "debug")]
+    pub quiet: bool,
+
+    /// Print additional debug output
+    #[arg(long, global = true)]
+    pub debug: bool,
+
+    /// Path to a file containing the session cookie
+    #[arg(long, global = true, value_name = "FILE")]
+    pub session_file: Option<String>,
+
+    /// Puzzle year [default: year of current/last Advent of Code event]
+    #[arg(short, long, global = true, env = "AOC_YEAR")]
+    pub year: Option<i32>,
+
+    /// Puzzle day [default: current/last day of the event year]
+    #[arg(short, long, global = true, env = "AOC_DAY")]
+    pub day: Option<u32>,
+
+    /// Width to wrap puzzle text to
+    #[arg(short, long, global = true, value_name = "WIDTH")]
+    pub width: Option<usize>,
+
+    /// Overwrite any existing files when downloading or scaffolding
+    #[arg(short, long, global = true)]
+    pub overwrite: bool,
+
+    /// Show the raw, unformatted HTML of the puzzle
+    #[arg(long, global = true)]
+    pub show_html_markup: bool,
+
+    /// Only download the puzzle input, not the puzzle markdown
+    #[arg(long, global = true)]
+    pub input_only: bool,
+
+    /// Only download the puzzle markdown, not the puzzle input
+    #[arg(long, global = true)]
+    pub puzzle_only: bool,
+
+    /// Path to save the puzzle input to
+    #[arg(long, global = true, default_value = "input.txt")]
+    pub input_file: String,
+
+    /// Path to save the puzzle markdown to
+    #[arg(long, global = true, default_value = "puzzle.md")]
+    pub puzzle_file: String,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Show which puzzles have been unlocked/completed for the event year
+    Calendar,
+
+    /// Save puzzle markdown and/or input to files
+    Download {
+        /// Download every day (1-25) of the event instead of a single day
+        #[arg(long, conflicts_with = "days")]
+        all: bool,
+
+        /// Download a range of days, e.g. `1-25` or a single day, e.g. `5`
+        #[arg(long, value_name = "RANGE")]
+        days: Option<DayRange>,
+    },
+
+    /// Submit an answer for a puzzle part
+    Submit {
+        /// Puzzle part, "1" or "2"
+        part: String,
+        answer: String,
+    },
+
+    /// Show the members of a private leaderboard
+    PrivateLeaderboard {
+        #[arg(value_name = "LEADERBOARD_ID")]
+        leaderboard_id: u64,
+    },
+
+    /// Show the puzzle page for the current/selected day
+    Read,
+
+    /// Run a local solver against the cached puzzle input and time it
+    Run {
+        /// Command (and arguments) used to invoke the solver, e.g.
+        /// `cargo run --release --bin day01`. Falls back to the `solver`
+        /// key in `aoc-cli.toml` if omitted
+        #[arg(trailing_var_arg = true)]
+        solver: Vec<String>,
+
+        /// Benchmark the solver instead of running it once: re-run it,
+        /// collecting samples, until ~1 second has elapsed and at least
+        /// 10 samples have been gathered
+        #[arg(long)]
+        time: bool,
+    },
+
+    /// Scaffold a solution file for the selected year/day from a template
+    Scaffold {
+        /// Path to a custom template file; falls back to the `template`
+        /// key in `aoc-cli.toml`, then a built-in default
+        #[arg(long, value_name = "FILE")]
+        template: Option<String>,
+
+        /// Also download the puzzle input alongside the scaffolded file
+        #[arg(long)]
+        download_input: bool,
+    },
+
+    /// Generate a shell completion script
+    GenerateCompletion(GenerateCompletionCommand),
+}
+
+#[derive(Debug, Parser)]
+pub struct GenerateCompletionCommand {
+    #[arg(value_enum)]
+    pub shell: Shell,
+}
+
+/// An inclusive range of puzzle days, e.g. `1-25` or a single day like `5`.
+#[derive(Debug, Clone, Copy)]
+pub struct DayRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl DayRange {
+    /// The whole Advent of Code event, days 1 through 25.
+    pub fn whole_event() -> Self {
+        DayRange { start: 1, end: 25 }
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u32> {
+        self.start..=self.end
+    }
+}
+
+impl std::str::FromStr for DayRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = match s.split_once('-') {
+            Some((start, end)) => (start, end),
+            None => (s, s),
+        };
+
+        let start: u32 = start
+            .parse()
+            .map_err(|_| format!("invalid day range `{s}`"))?;
+        let end: u32 = end
+            .parse()
+            .map_err(|_| format!("invalid day range `{s}`"))?;
+
+        if start < 1 || end > 25 || start > end {
+            return Err(format!(
+                "invalid day range `{s}`: days must be between 1 and 25, start before end"
+            ));
+        }
+
+        Ok(DayRange { start, end })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_range() {
+        let range: DayRange = "1-25".parse().unwrap();
+        assert_eq!(range.start, 1);
+        assert_eq!(range.end, 25);
+    }
+
+    #[test]
+    fn parses_a_single_day() {
+        let range: DayRange = "5".parse().unwrap();
+        assert_eq!(range.start, 5);
+        assert_eq!(range.end, 5);
+    }
+
+    #[test]
+    fn rejects_a_day_before_1() {
+        assert!("0-25".parse::<DayRange>().is_err());
+    }
+
+    #[test]
+    fn rejects_start_after_end() {
+        assert!("5-3".parse::<DayRange>().is_err());
+    }
+
+    #[test]
+    fn rejects_a_day_after_25() {
+        assert!("1-26".parse::<DayRange>().is_err());
+    }
+}
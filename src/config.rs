@@ -0,0 +1,38 @@
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+/// Project-level configuration, read from `aoc-cli.toml` in the current
+/// directory if one exists.
+///
+/// This lets settings that rarely change between invocations (like the
+/// command used to run a day's solver) live alongside the project instead
+/// of being passed on every command line.
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// Command (and arguments) used to run a day's solver, e.g.
+    /// `"cargo run --release --bin day01"`. Used by `aoc run`/`aoc run --time`
+    /// when no solver command is given on the command line.
+    pub solver: Option<String>,
+
+    /// Path to a custom scaffold template, used by `aoc scaffold` when no
+    /// `--template` flag is given.
+    pub template: Option<String>,
+}
+
+impl Config {
+    const FILE_NAME: &'static str = "aoc-cli.toml";
+
+    /// Load the config from `aoc-cli.toml` in the current directory,
+    /// falling back to defaults if the file is missing or unreadable.
+    pub fn load() -> Self {
+        Self::load_from(Path::new(Self::FILE_NAME))
+    }
+
+    fn load_from(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+}
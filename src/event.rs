@@ -0,0 +1,23 @@
+use chrono::{Datelike, Duration, Utc};
+
+/// Resolve the (year, day) a command should operate on: an explicit value
+/// always wins; otherwise fall back to whichever puzzle is currently
+/// unlocked, the same "latest" semantics `aoc-client`'s own
+/// `latest_event_year`/`latest_puzzle_day` builder steps use internally
+/// (and which aren't exposed back to callers once a client is built).
+///
+/// AoC puzzles unlock at midnight US/Eastern, which is a fixed UTC-5
+/// throughout December (no DST to account for), so "now" is shifted by
+/// that offset before reading off the year/day.
+pub fn resolve_year_day(year: Option<i32>, day: Option<u32>) -> (i32, u32) {
+    let now = Utc::now() - Duration::hours(5);
+
+    let default_year = if now.month() == 12 {
+        now.year()
+    } else {
+        now.year() - 1
+    };
+    let default_day = if now.month() == 12 { now.day().min(25) } else { 25 };
+
+    (year.unwrap_or(default_year), day.unwrap_or(default_day))
+}
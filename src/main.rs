@@ -1,23 +1,40 @@
 mod args;
+mod config;
+mod event;
+mod scaffold;
+mod solver;
+mod submission_cache;
 
-use aoc_client::{AocClient, AocError, AocResult};
-use args::{Args, Command, GenerateCompletionCommand};
+use aoc_client::{AocClient, AocError, AocResult, SubmissionOutcome};
+use args::{Args, Command, DayRange, GenerateCompletionCommand};
 use clap::{crate_description, crate_name, CommandFactory, Parser};
 use clap_complete::generate;
 use env_logger::{Builder, Env};
+use event::resolve_year_day;
 use exit_code::*;
 use log::{error, info, warn, LevelFilter};
+use scaffold::scaffold;
+use solver::run_solver;
+use std::env;
 use std::io;
 use std::process::exit;
+use submission_cache::{SubmissionCache, Verdict};
 
 fn main() {
+    // Load `.env` (if any) before argument parsing, so `--year`/`--day`'s
+    // `env = "AOC_..."` fallbacks and the session-cookie env lookup in
+    // `build_client` both see it.
+    dotenvy::dotenv().ok();
+
     let args = Args::parse();
 
     setup_log(&args);
 
     info!("🎄 {} - {}", crate_name!(), crate_description!());
 
-    match build_client(&args).and_then(|client| run(&args, client)) {
+    let (year, day) = resolve_year_day(args.year, args.day);
+
+    match run(&args, year, day) {
         Ok(_) => exit(SUCCESS),
         Err(err) => {
             error!("🔔 {err}");
@@ -65,60 +82,220 @@ fn setup_log(args: &Args) {
     log_builder.format_timestamp(None).init();
 }
 
-fn build_client(args: &Args) -> AocResult<AocClient> {
+/// Read a session cookie from `AOC_SESSION`/`AOC_TOKEN`, in that order,
+/// either set directly or loaded from a `.env` file in the working
+/// directory.
+fn session_from_env() -> Option<String> {
+    env::var("AOC_SESSION")
+        .or_else(|_| env::var("AOC_TOKEN"))
+        .ok()
+}
+
+fn build_client(args: &Args, year: i32, day: u32) -> AocResult<AocClient> {
+    build_client_with(args, year, day, &args.input_file, &args.puzzle_file)
+}
+
+/// Like [`build_client`], but lets the caller pin the destination filenames
+/// instead of taking them from `args`. Used by [`download_range`] to build
+/// a fresh, differently-named client per day.
+fn build_client_with(
+    args: &Args,
+    year: i32,
+    day: u32,
+    input_file: &str,
+    puzzle_file: &str,
+) -> AocResult<AocClient> {
     let mut builder = AocClient::builder();
 
     if let Some(file) = &args.session_file {
         builder.session_cookie_from_file(file)?;
+    } else if let Some(session) = session_from_env() {
+        builder.session_cookie(&session)?;
     } else {
         builder.session_cookie_from_default_locations()?;
     }
 
-    match (args.year, args.day) {
-        (Some(year), Some(day)) => builder.year(year)?.day(day)?,
-        (Some(year), None) => builder.year(year)?.latest_puzzle_day()?,
-        (None, Some(day)) => builder.latest_event_year()?.day(day)?,
-        (None, None) => builder.latest_puzzle_day()?,
-    };
+    builder.year(year)?.day(day)?;
 
     if let Some(width) = args.width {
         builder.output_width(width)?;
     }
 
     builder
-        .input_filename(&args.input_file)
-        .puzzle_filename(&args.puzzle_file)
+        .input_filename(input_file)
+        .puzzle_filename(puzzle_file)
         .overwrite_files(args.overwrite)
         .show_html_markup(args.show_html_markup)
         .build()
 }
 
-fn run(args: &Args, client: AocClient) -> AocResult<()> {
+/// Dispatch the selected command. `aoc run` and `aoc scaffold` (without
+/// `--download-input`) work offline and never build an [`AocClient`]; every
+/// other command builds one for the resolved year/day before it runs.
+fn run(args: &Args, year: i32, day: u32) -> AocResult<()> {
     match &args.command {
-        Some(command) => match command {
-            Command::Calendar => client.show_calendar(),
-            Command::Download => {
-                if !args.input_only {
-                    client.save_puzzle_markdown()?;
+        Some(Command::Run { solver, time }) => run_solver(args, solver, *time),
+        Some(Command::Scaffold {
+            template,
+            download_input: false,
+        }) => scaffold(args, None, year, day, template.as_deref(), false),
+        Some(command) => {
+            let client = build_client(args, year, day)?;
+
+            match command {
+                Command::Calendar => client.show_calendar(),
+                Command::Download { all, days } => {
+                    let range = if *all {
+                        Some(DayRange::whole_event())
+                    } else {
+                        *days
+                    };
+
+                    match range {
+                        Some(range) => download_range(args, range),
+                        None => {
+                            if !args.input_only {
+                                client.save_puzzle_markdown()?;
+                            }
+                            if !args.puzzle_only {
+                                client.save_input()?;
+                            }
+                            Ok(())
+                        }
+                    }
                 }
-                if !args.puzzle_only {
-                    client.save_input()?;
+                Command::Submit { part, answer } => {
+                    submit_answer(&client, year, day, part, answer)
+                }
+                Command::PrivateLeaderboard { leaderboard_id } => {
+                    client.show_private_leaderboard(*leaderboard_id)
+                }
+                Command::Read => client.show_puzzle(),
+                Command::Scaffold {
+                    template,
+                    download_input,
+                } => scaffold(
+                    args,
+                    Some(&client),
+                    year,
+                    day,
+                    template.as_deref(),
+                    *download_input,
+                ),
+                Command::Run { .. } => unreachable!("handled above"),
+                Command::GenerateCompletion(command) => {
+                    generate_completion(command);
+                    Ok(())
                 }
-                Ok(())
-            }
-            Command::Submit { part, answer } => {
-                client.submit_answer_and_show_outcome(part, answer)
-            }
-            Command::PrivateLeaderboard { leaderboard_id } => {
-                client.show_private_leaderboard(*leaderboard_id)
-            }
-            Command::Read => client.show_puzzle(),
-            Command::GenerateCompletion(command) => {
-                generate_completion(command);
-                Ok(())
             }
-        },
-        None => client.show_puzzle(),
+        }
+        None => build_client(args, year, day)?.show_puzzle(),
+    }
+}
+
+/// Submit an answer, short-circuiting against the local [`SubmissionCache`]
+/// when the outcome is already known, and recording the outcome of any
+/// submission that does go out over the network.
+///
+/// `year`/`day` are the exact values `client` was built with (see `main`),
+/// so the cache is keyed identically to whatever puzzle the client submits
+/// against - not a second, independently resolved guess that could drift
+/// from it at a date boundary.
+fn submit_answer(
+    client: &AocClient,
+    year: i32,
+    day: u32,
+    part: &str,
+    answer: &str,
+) -> AocResult<()> {
+    let mut cache = SubmissionCache::load();
+
+    match cache.check(year, day, part, answer) {
+        Verdict::AlreadyCorrect => {
+            info!("✅ {answer} was already submitted and accepted for part {part}");
+            return Ok(());
+        }
+        Verdict::AlreadyWrong => {
+            warn!(
+                "❌ {answer} was already submitted and rejected for part {part}, \
+                not resubmitting"
+            );
+            return Ok(());
+        }
+        Verdict::Unknown => {}
+    }
+
+    let outcome = client.submit_answer(part, answer)?;
+    show_submission_outcome(&outcome, part, answer);
+    cache.record(year, day, part, answer, &outcome);
+
+    Ok(())
+}
+
+/// Report a submission outcome ourselves: unlike
+/// `submit_answer_and_show_outcome`, `submit_answer` only returns the
+/// outcome, it doesn't print anything.
+fn show_submission_outcome(outcome: &SubmissionOutcome, part: &str, answer: &str) {
+    match outcome {
+        SubmissionOutcome::Correct => info!("⭐ {answer} is correct for part {part}!"),
+        SubmissionOutcome::Incorrect => {
+            warn!("❌ {answer} is not the right answer for part {part}")
+        }
+        SubmissionOutcome::WrongLevel => {
+            warn!("⚠️ Part {part} isn't available to submit for right now")
+        }
+        SubmissionOutcome::Wait => {
+            warn!("⏳ You're submitting too fast, wait a bit before trying again")
+        }
+    }
+}
+
+/// Download every day in `range`, skipping days that aren't unlocked yet.
+///
+/// Each day gets its own client (the puzzle year/day is fixed per request)
+/// and its own filenames, so a single invocation doesn't clobber the same
+/// `input.txt`/`puzzle.md` 25 times over.
+fn download_range(args: &Args, range: DayRange) -> AocResult<()> {
+    let (year, _) = resolve_year_day(args.year, None);
+
+    for day in range.days() {
+        let input_file = day_templated_filename(&args.input_file, day);
+        let puzzle_file = day_templated_filename(&args.puzzle_file, day);
+
+        let client =
+            match build_client_with(args, year, day, &input_file, &puzzle_file) {
+                Ok(client) => client,
+                Err(AocError::LockedPuzzle(..)) => {
+                    warn!("🔒 Day {day} isn't unlocked yet, skipping");
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+        if !args.input_only {
+            client.save_puzzle_markdown()?;
+        }
+        if !args.puzzle_only {
+            client.save_input()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Insert the (zero-padded) day number into a filename template.
+///
+/// A `{day}` placeholder is replaced directly; otherwise the day is
+/// inserted before the extension, e.g. `input.txt` -> `input_05.txt`.
+fn day_templated_filename(template: &str, day: u32) -> String {
+    let day = format!("{day:02}");
+
+    if template.contains("{day}") {
+        template.replace("{day}", &day)
+    } else if let Some((stem, ext)) = template.rsplit_once('.') {
+        format!("{stem}_{day}.{ext}")
+    } else {
+        format!("{template}_{day}")
     }
 }
 
@@ -129,3 +306,26 @@ fn generate_completion(command: &GenerateCompletionCommand) {
     let bin_name = env!("CARGO_BIN_NAME");
     generate(shell, &mut app, bin_name, &mut io::stdout());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replaces_a_day_placeholder() {
+        assert_eq!(
+            day_templated_filename("input_{day}.txt", 5),
+            "input_05.txt"
+        );
+    }
+
+    #[test]
+    fn inserts_before_the_extension_when_there_is_no_placeholder() {
+        assert_eq!(day_templated_filename("input.txt", 5), "input_05.txt");
+    }
+
+    #[test]
+    fn appends_when_there_is_no_extension_or_placeholder() {
+        assert_eq!(day_templated_filename("input", 5), "input_05");
+    }
+}
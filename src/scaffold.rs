@@ -0,0 +1,92 @@
+use crate::args::Args;
+use crate::config::Config;
+use aoc_client::{AocClient, AocError, AocResult};
+use log::info;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Built-in scaffold used when neither `--template` nor a `template` key in
+/// `aoc-cli.toml` is given.
+const DEFAULT_TEMPLATE: &str = r#"//! Advent of Code {year}, day {day}.
+
+fn part1(input: &str) -> String {
+    todo!()
+}
+
+fn part2(input: &str) -> String {
+    todo!()
+}
+
+fn main() {
+    let input = std::fs::read_to_string("input.txt").expect("couldn't read input.txt");
+
+    println!("Part 1: {}", part1(&input));
+    println!("Part 2: {}", part2(&input));
+}
+"#;
+
+/// Write a solution file for `year`/`day` from a template, optionally
+/// downloading the puzzle input alongside it.
+///
+/// `year`/`day` are passed in rather than resolved here so they always
+/// match whatever `client` was built with (see `main::run`). `client` is
+/// only needed when `download_input` is set - scaffolding on its own
+/// doesn't talk to adventofcode.com, so `main::run` doesn't build one.
+pub fn scaffold(
+    args: &Args,
+    client: Option<&AocClient>,
+    year: i32,
+    day: u32,
+    template: Option<&str>,
+    download_input: bool,
+) -> AocResult<()> {
+    let config = Config::load();
+    let template_path = template.or(config.template.as_deref());
+
+    let contents = match template_path {
+        Some(path) => fs::read_to_string(path).map_err(|source| AocError::FileWriteError {
+            path: PathBuf::from(path),
+            source,
+        })?,
+        None => DEFAULT_TEMPLATE.to_owned(),
+    };
+
+    let contents = contents
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &format!("{day:02}"));
+
+    let destination = PathBuf::from(format!("src/year{year}/day{day:02}.rs"));
+
+    if destination.exists() && !args.overwrite {
+        return Err(AocError::FileWriteError {
+            path: destination,
+            source: io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "already exists, pass --overwrite to replace it",
+            ),
+        });
+    }
+
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent).map_err(|source| AocError::FileWriteError {
+            path: parent.to_path_buf(),
+            source,
+        })?;
+    }
+
+    fs::write(&destination, contents).map_err(|source| AocError::FileWriteError {
+        path: destination.clone(),
+        source,
+    })?;
+
+    info!("📄 Scaffolded {}", destination.display());
+
+    if download_input {
+        client
+            .expect("download_input implies main::run built a client")
+            .save_input()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use crate::args::Args;
+use crate::config::Config;
+use aoc_client::{AocError, AocResult};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::{Command as Process, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Minimum wall-clock time to spend benchmarking, even if fewer than
+/// [`MIN_SAMPLES`] runs have completed.
+const MIN_BENCH_DURATION: Duration = Duration::from_secs(1);
+
+/// Minimum number of samples to collect when benchmarking, even if
+/// [`MIN_BENCH_DURATION`] has already elapsed.
+const MIN_SAMPLES: usize = 10;
+
+/// Run a solver command against the cached puzzle input, optionally
+/// benchmarking it with `--time`.
+pub fn run_solver(args: &Args, solver: &[String], time: bool) -> AocResult<()> {
+    let config = Config::load();
+
+    let command_line = resolve_solver_command(solver, &config).ok_or(
+        AocError::ClientFieldMissing(
+            "solver (pass one after `aoc run`, or set `solver` in aoc-cli.toml)",
+        ),
+    )?;
+
+    let input =
+        std::fs::read_to_string(&args.input_file).map_err(|source| AocError::FileWriteError {
+            path: PathBuf::from(&args.input_file),
+            source,
+        })?;
+
+    if time {
+        bench_solver(&command_line, &input)
+    } else {
+        let (output, duration) = run_once(&command_line, &input)?;
+        print!("{output}");
+        println!("⏱️ Solved in {duration:?}");
+        Ok(())
+    }
+}
+
+/// Re-run the solver, collecting samples, until at least [`MIN_SAMPLES`]
+/// have been gathered *and* [`MIN_BENCH_DURATION`] has elapsed, then report
+/// the minimum and mean duration.
+fn bench_solver(command_line: &[String], input: &str) -> AocResult<()> {
+    let mut durations = Vec::new();
+    let start = Instant::now();
+
+    while durations.len() < MIN_SAMPLES || start.elapsed() < MIN_BENCH_DURATION {
+        let (_, duration) = run_once(command_line, input)?;
+        durations.push(duration);
+    }
+
+    let total: Duration = durations.iter().sum();
+    let mean = total / durations.len() as u32;
+    let min = durations.iter().min().copied().unwrap_or_default();
+
+    println!(
+        "⏱️ {} samples — min {min:?}, mean {mean:?}",
+        durations.len()
+    );
+
+    Ok(())
+}
+
+/// Spawn the solver, feed it `input` on stdin, and return its stdout
+/// together with how long it took.
+///
+/// The write happens on its own thread, concurrently with reading stdout:
+/// writing all of `input` up front and only then calling
+/// `wait_with_output` deadlocks as soon as the solver writes more than a
+/// pipe buffer's worth of stdout before it's done reading stdin.
+fn run_once(command_line: &[String], input: &str) -> AocResult<(String, Duration)> {
+    let start = Instant::now();
+
+    let mut child = Process::new(&command_line[0])
+        .args(&command_line[1..])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|_| {
+            AocError::ClientFieldMissing(
+                "solver (the configured command couldn't be started - is it on PATH?)",
+            )
+        })?;
+
+    let mut stdin = child.stdin.take().expect("child stdin was piped");
+    let input = input.to_owned();
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+    let output = child
+        .wait_with_output()
+        .map_err(|source| AocError::FileWriteError {
+            path: PathBuf::from(&command_line[0]),
+            source,
+        })?;
+    let duration = start.elapsed();
+
+    if let Err(err) = writer.join().expect("stdin writer thread panicked") {
+        // A solver that exits without draining stdin makes the write end
+        // in a BrokenPipe error even though it ran fine - only treat that
+        // as fatal if the solver itself didn't exit successfully either.
+        if err.kind() != io::ErrorKind::BrokenPipe || !output.status.success() {
+            return Err(AocError::FileWriteError {
+                path: PathBuf::from(&command_line[0]),
+                source: err,
+            });
+        }
+    }
+
+    Ok((String::from_utf8_lossy(&output.stdout).into_owned(), duration))
+}
+
+/// Prefer a solver command given on the command line; fall back to the
+/// `solver` key in `aoc-cli.toml`.
+fn resolve_solver_command(solver: &[String], config: &Config) -> Option<Vec<String>> {
+    if !solver.is_empty() {
+        return Some(solver.to_vec());
+    }
+
+    config
+        .solver
+        .as_ref()
+        .map(|command| command.split_whitespace().map(str::to_owned).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefers_the_command_line_solver() {
+        let config = Config {
+            solver: Some("echo fallback".to_owned()),
+            template: None,
+        };
+
+        let resolved = resolve_solver_command(&["echo".to_owned(), "cli".to_owned()], &config);
+
+        assert_eq!(resolved, Some(vec!["echo".to_owned(), "cli".to_owned()]));
+    }
+
+    #[test]
+    fn falls_back_to_the_config_solver() {
+        let config = Config {
+            solver: Some("cargo run --release".to_owned()),
+            template: None,
+        };
+
+        let resolved = resolve_solver_command(&[], &config);
+
+        assert_eq!(
+            resolved,
+            Some(vec![
+                "cargo".to_owned(),
+                "run".to_owned(),
+                "--release".to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn none_when_neither_is_set() {
+        let config = Config {
+            solver: None,
+            template: None,
+        };
+
+        assert_eq!(resolve_solver_command(&[], &config), None);
+    }
+}
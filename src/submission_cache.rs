@@ -0,0 +1,218 @@
+//! A small persistent cache of submitted answers.
+//!
+//! Known limitation: AoC's own rejection feedback ("too high"/"too low")
+//! isn't available through `aoc_client::SubmissionOutcome`, which collapses
+//! every rejection into `Incorrect` with no numeric signal. So unlike what
+//! was originally asked for, this cache can't reject an out-of-range guess
+//! before it's sent - it only recognizes answers it has already seen
+//! exactly, via [`SubmissionCache::check`].
+
+use aoc_client::SubmissionOutcome;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// A previously submitted answer and what AoC said about it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Submission {
+    answer: String,
+    outcome: CachedOutcome,
+}
+
+/// The part of [`aoc_client::SubmissionOutcome`] worth remembering between
+/// runs. `Wait` (AoC's submit-too-fast rate limit) is transient and says
+/// nothing about the answer itself, so it's never cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum CachedOutcome {
+    Correct,
+    Incorrect,
+    WrongLevel,
+}
+
+impl CachedOutcome {
+    fn from_outcome(outcome: &SubmissionOutcome) -> Option<Self> {
+        match outcome {
+            SubmissionOutcome::Correct => Some(CachedOutcome::Correct),
+            SubmissionOutcome::Incorrect => Some(CachedOutcome::Incorrect),
+            SubmissionOutcome::WrongLevel => Some(CachedOutcome::WrongLevel),
+            SubmissionOutcome::Wait => None,
+        }
+    }
+}
+
+/// What the cache already knows about a guess, before it's sent to AoC.
+pub enum Verdict {
+    /// Nothing useful is cached; submit as normal.
+    Unknown,
+    /// This exact answer was already accepted.
+    AlreadyCorrect,
+    /// This exact answer was already rejected.
+    AlreadyWrong,
+}
+
+/// A small persistent cache of submitted answers, so repeated `aoc submit`
+/// invocations don't re-send answers AoC has already judged and don't burn
+/// guesses against its rate limit.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SubmissionCache {
+    submissions: HashMap<String, Vec<Submission>>,
+}
+
+impl SubmissionCache {
+    fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("aoc-cli").join("submissions.json"))
+    }
+
+    /// Load the cache from disk, or start with an empty one if it doesn't
+    /// exist yet or can't be read.
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) {
+        let Some(path) = Self::path() else {
+            return;
+        };
+
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        if let Ok(json) = serde_json::to_string_pretty(self) {
+            let _ = fs::write(path, json);
+        }
+    }
+
+    /// Check what's already known about `answer` for this puzzle part
+    /// before it's submitted.
+    pub fn check(&self, year: i32, day: u32, part: &str, answer: &str) -> Verdict {
+        let Some(submissions) = self.submissions.get(&Self::key(year, day, part)) else {
+            return Verdict::Unknown;
+        };
+
+        match submissions.iter().find(|s| s.answer == answer) {
+            Some(previous) if previous.outcome == CachedOutcome::Correct => Verdict::AlreadyCorrect,
+            Some(previous) if previous.outcome == CachedOutcome::Incorrect => Verdict::AlreadyWrong,
+            _ => Verdict::Unknown,
+        }
+    }
+
+    /// Remember the outcome AoC reported for this submission, if it's worth
+    /// remembering (see [`CachedOutcome::from_outcome`]).
+    pub fn record(
+        &mut self,
+        year: i32,
+        day: u32,
+        part: &str,
+        answer: &str,
+        outcome: &SubmissionOutcome,
+    ) {
+        let Some(outcome) = CachedOutcome::from_outcome(outcome) else {
+            return;
+        };
+
+        self.submissions
+            .entry(Self::key(year, day, part))
+            .or_default()
+            .push(Submission {
+                answer: answer.to_owned(),
+                outcome,
+            });
+
+        self.save();
+    }
+
+    fn key(year: i32, day: u32, part: &str) -> String {
+        format!("{year}-{day}-{part}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_answer_is_unknown() {
+        let cache = SubmissionCache::default();
+
+        assert!(matches!(
+            cache.check(2023, 1, "1", "42"),
+            Verdict::Unknown
+        ));
+    }
+
+    #[test]
+    fn correct_answer_is_remembered() {
+        let mut cache = SubmissionCache::default();
+        cache.submissions.insert(
+            SubmissionCache::key(2023, 1, "1"),
+            vec![Submission {
+                answer: "42".to_owned(),
+                outcome: CachedOutcome::Correct,
+            }],
+        );
+
+        assert!(matches!(
+            cache.check(2023, 1, "1", "42"),
+            Verdict::AlreadyCorrect
+        ));
+    }
+
+    #[test]
+    fn wrong_answer_is_remembered() {
+        let mut cache = SubmissionCache::default();
+        cache.submissions.insert(
+            SubmissionCache::key(2023, 1, "1"),
+            vec![Submission {
+                answer: "42".to_owned(),
+                outcome: CachedOutcome::Incorrect,
+            }],
+        );
+
+        assert!(matches!(
+            cache.check(2023, 1, "1", "42"),
+            Verdict::AlreadyWrong
+        ));
+    }
+
+    #[test]
+    fn a_different_answer_for_the_same_part_is_unknown() {
+        let mut cache = SubmissionCache::default();
+        cache.submissions.insert(
+            SubmissionCache::key(2023, 1, "1"),
+            vec![Submission {
+                answer: "42".to_owned(),
+                outcome: CachedOutcome::Incorrect,
+            }],
+        );
+
+        assert!(matches!(
+            cache.check(2023, 1, "1", "43"),
+            Verdict::Unknown
+        ));
+    }
+
+    #[test]
+    fn wait_outcome_is_never_recorded() {
+        let mut cache = SubmissionCache::default();
+        cache.record(2023, 1, "1", "42", &SubmissionOutcome::Wait);
+
+        assert!(cache.submissions.is_empty());
+    }
+
+    #[test]
+    fn correct_outcome_is_recorded_and_then_remembered() {
+        let mut cache = SubmissionCache::default();
+        cache.record(2023, 1, "1", "42", &SubmissionOutcome::Correct);
+
+        assert!(matches!(
+            cache.check(2023, 1, "1", "42"),
+            Verdict::AlreadyCorrect
+        ));
+    }
+}